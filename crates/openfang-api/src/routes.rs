@@ -264,7 +264,7 @@ pub async fn send_message(
     let kernel_handle: Arc<dyn KernelHandle> = state.kernel.clone() as Arc<dyn KernelHandle>;
     match state
         .kernel
-        .send_message_with_handle(agent_id, &req.message, Some(kernel_handle))
+        .send_message_with_overrides(agent_id, &req.message, Some(kernel_handle), req.model_params)
         .await
     {
         Ok(result) => {
@@ -286,6 +286,7 @@ pub async fn send_message(
                     input_tokens: result.total_usage.input_tokens,
                     output_tokens: result.total_usage.output_tokens,
                     iterations: result.iterations,
+                    citations: result.citations,
                 })),
             )
         }
@@ -6052,8 +6053,10 @@ pub async fn test_provider(
                 tools: vec![],
                 max_tokens: 1,
                 temperature: 0.0,
+                top_p: None,
                 system: None,
                 thinking: None,
+                reasoning: None,
             };
             match driver.complete(test_req).await {
                 Ok(_) => {
@@ -37,6 +37,10 @@ pub struct MessageRequest {
     /// Optional file attachments (uploaded via /upload endpoint).
     #[serde(default)]
     pub attachments: Vec<AttachmentRef>,
+    /// Per-request model parameter overrides (temperature/top_p/max_tokens).
+    /// Merge order is request > agent manifest > provider default.
+    #[serde(default)]
+    pub model_params: Option<openfang_types::agent::ModelParamOverrides>,
 }
 
 /// Response from sending a message.
@@ -46,6 +50,8 @@ pub struct MessageResponse {
     pub input_tokens: u64,
     pub output_tokens: u64,
     pub iterations: u32,
+    /// Sources cited by recalled memories folded into this response.
+    pub citations: Vec<openfang_types::message::Citation>,
 }
 
 /// Request to install a skill from the marketplace.
@@ -1129,6 +1129,20 @@ impl OpenFangKernel {
         agent_id: AgentId,
         message: &str,
         kernel_handle: Option<Arc<dyn KernelHandle>>,
+    ) -> KernelResult<AgentLoopResult> {
+        self.send_message_with_overrides(agent_id, message, kernel_handle, None)
+            .await
+    }
+
+    /// Send a message with an optional kernel handle and per-request model
+    /// parameter overrides (temperature/top_p/max_tokens), merged over the
+    /// agent manifest's `[model]` defaults for LLM agents only.
+    pub async fn send_message_with_overrides(
+        &self,
+        agent_id: AgentId,
+        message: &str,
+        kernel_handle: Option<Arc<dyn KernelHandle>>,
+        model_overrides: Option<openfang_types::agent::ModelParamOverrides>,
     ) -> KernelResult<AgentLoopResult> {
         // Enforce quota before running the agent loop
         self.scheduler
@@ -1147,7 +1161,7 @@ impl OpenFangKernel {
             self.execute_python_agent(&entry, agent_id, message).await
         } else {
             // Default: LLM agent loop (builtin:chat or any unrecognized module)
-            self.execute_llm_agent(&entry, agent_id, message, kernel_handle)
+            self.execute_llm_agent(&entry, agent_id, message, kernel_handle, model_overrides)
                 .await
         };
 
@@ -1655,14 +1669,12 @@ impl OpenFangKernel {
 
         Ok(AgentLoopResult {
             response,
-            total_usage: openfang_types::message::TokenUsage {
-                input_tokens: 0,
-                output_tokens: 0,
-            },
+            total_usage: openfang_types::message::TokenUsage::default(),
             iterations: 1,
             cost_usd: None,
             silent: false,
             directives: Default::default(),
+            citations: Vec::new(),
         })
     }
 
@@ -1715,14 +1727,12 @@ impl OpenFangKernel {
 
         Ok(AgentLoopResult {
             response: result.response,
-            total_usage: openfang_types::message::TokenUsage {
-                input_tokens: 0,
-                output_tokens: 0,
-            },
+            total_usage: openfang_types::message::TokenUsage::default(),
             cost_usd: None,
             iterations: 1,
             silent: false,
             directives: Default::default(),
+            citations: Vec::new(),
         })
     }
 
@@ -1733,6 +1743,7 @@ impl OpenFangKernel {
         agent_id: AgentId,
         message: &str,
         kernel_handle: Option<Arc<dyn KernelHandle>>,
+        model_overrides: Option<openfang_types::agent::ModelParamOverrides>,
     ) -> KernelResult<AgentLoopResult> {
         // Check metering quota before starting
         self.metering
@@ -1888,8 +1899,10 @@ impl OpenFangKernel {
                 tools: tools.clone(),
                 max_tokens: manifest.model.max_tokens,
                 temperature: manifest.model.temperature,
+                top_p: manifest.model.top_p,
                 system: Some(manifest.model.system_prompt.clone()),
-                thinking: None,
+                thinking: manifest.model.thinking_config(),
+                reasoning: manifest.model.reasoning,
             };
             let (complexity, routed_model) = router.select_model(&probe);
             info!(
@@ -1901,6 +1914,25 @@ impl OpenFangKernel {
             manifest.model.model = routed_model;
         }
 
+        // Apply per-request model parameter overrides (request > agent manifest > provider
+        // default), validated against the catalog's known bounds for the resolved model.
+        if let Some(overrides) = model_overrides {
+            let max_tokens = overrides.max_tokens.unwrap_or(manifest.model.max_tokens);
+            let temperature = overrides.temperature.unwrap_or(manifest.model.temperature);
+            let top_p = overrides.top_p.or(manifest.model.top_p);
+            self.model_catalog
+                .read()
+                .unwrap_or_else(|e| e.into_inner())
+                .validate_model_params(&manifest.model.model, max_tokens, temperature, top_p)
+                .map_err(|e| KernelError::OpenFang(OpenFangError::Config(e)))?;
+            manifest.model.max_tokens = max_tokens;
+            manifest.model.temperature = temperature;
+            manifest.model.top_p = top_p;
+            if let Some(reasoning) = overrides.reasoning {
+                manifest.model.reasoning = Some(reasoning);
+            }
+        }
+
         let driver = self.resolve_driver(&manifest)?;
 
         // Look up model's actual context window from the catalog
@@ -2588,6 +2620,9 @@ impl OpenFangKernel {
                 model: hand_model,
                 max_tokens: def.agent.max_tokens,
                 temperature: def.agent.temperature,
+                top_p: None,
+                reasoning: None,
+                show_reasoning: false,
                 system_prompt: def.agent.system_prompt.clone(),
                 api_key_env: def.agent.api_key_env.clone(),
                 base_url: def.agent.base_url.clone(),
@@ -141,6 +141,7 @@ mod tests {
             &TokenUsage {
                 input_tokens: 100,
                 output_tokens: 50,
+                reasoning_tokens: 0,
             },
         );
         let (tokens, _) = scheduler.get_usage(id).unwrap();
@@ -161,6 +162,7 @@ mod tests {
             &TokenUsage {
                 input_tokens: 60,
                 output_tokens: 50,
+                reasoning_tokens: 0,
             },
         );
         assert!(scheduler.check_quota(id).is_err());
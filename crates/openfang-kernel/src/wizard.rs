@@ -159,6 +159,9 @@ impl SetupWizard {
                 model: model.to_string(),
                 max_tokens: 4096,
                 temperature: 0.7,
+                top_p: None,
+                reasoning: None,
+                show_reasoning: false,
                 system_prompt,
                 api_key_env: None,
                 base_url: None,
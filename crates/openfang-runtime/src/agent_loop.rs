@@ -89,6 +89,8 @@ pub struct AgentLoopResult {
     pub silent: bool,
     /// Reply directives extracted from the agent's response.
     pub directives: openfang_types::message::ReplyDirectives,
+    /// Citations for recalled memories folded into the prompt.
+    pub citations: Vec<openfang_types::message::Citation>,
 }
 
 /// Run the agent execution loop for a single user message.
@@ -201,6 +203,19 @@ pub async fn run_agent_loop(
         system_prompt.push_str(&crate::prompt_builder::build_memory_section(&mem_pairs));
     }
 
+    // Citations for recalled memories folded into the prompt above, so the final
+    // response can be traced back to its sources (span -> document -> offset).
+    let citations: Vec<openfang_types::message::Citation> = memories
+        .iter()
+        .map(|m| openfang_types::message::Citation {
+            source_id: m.id.to_string(),
+            document: m.scope.clone(),
+            offset_start: 0,
+            offset_end: m.content.chars().count(),
+            snippet: m.content.chars().take(160).collect(),
+        })
+        .collect();
+
     // Add the user message to session history
     session.messages.push(Message::user(user_message));
 
@@ -273,8 +288,10 @@ pub async fn run_agent_loop(
             tools: available_tools.to_vec(),
             max_tokens: manifest.model.max_tokens,
             temperature: manifest.model.temperature,
+            top_p: manifest.model.top_p,
             system: Some(system_prompt.clone()),
-            thinking: None,
+            thinking: manifest.model.thinking_config(),
+            reasoning: manifest.model.reasoning,
         };
 
         // Notify phase: Thinking
@@ -347,6 +364,7 @@ pub async fn run_agent_loop(
                             current_thread: parsed_directives.current_thread,
                             silent: true,
                         },
+                        citations: citations.clone(),
                     });
                 }
 
@@ -466,6 +484,7 @@ pub async fn run_agent_loop(
                     cost_usd: None,
                     silent: false,
                     directives: Default::default(),
+                citations: citations.clone(),
                 });
             }
             StopReason::ToolUse => {
@@ -698,6 +717,7 @@ pub async fn run_agent_loop(
                         cost_usd: None,
                         silent: false,
                         directives: Default::default(),
+                    citations: citations.clone(),
                     });
                 }
                 // Model hit token limit — add partial response and continue
@@ -1063,6 +1083,19 @@ pub async fn run_agent_loop_streaming(
         system_prompt.push_str(&crate::prompt_builder::build_memory_section(&mem_pairs));
     }
 
+    // Citations for recalled memories folded into the prompt above, so the final
+    // response can be traced back to its sources (span -> document -> offset).
+    let citations: Vec<openfang_types::message::Citation> = memories
+        .iter()
+        .map(|m| openfang_types::message::Citation {
+            source_id: m.id.to_string(),
+            document: m.scope.clone(),
+            offset_start: 0,
+            offset_end: m.content.chars().count(),
+            snippet: m.content.chars().take(160).collect(),
+        })
+        .collect();
+
     // Add the user message to session history
     session.messages.push(Message::user(user_message));
 
@@ -1147,8 +1180,10 @@ pub async fn run_agent_loop_streaming(
             tools: available_tools.to_vec(),
             max_tokens: manifest.model.max_tokens,
             temperature: manifest.model.temperature,
+            top_p: manifest.model.top_p,
             system: Some(system_prompt.clone()),
-            thinking: None,
+            thinking: manifest.model.thinking_config(),
+            reasoning: manifest.model.reasoning,
         };
 
         // Notify phase: Streaming (streaming variant always streams)
@@ -1225,6 +1260,7 @@ pub async fn run_agent_loop_streaming(
                             current_thread: parsed_directives_s.current_thread,
                             silent: true,
                         },
+                        citations: citations.clone(),
                     });
                 }
 
@@ -1342,6 +1378,7 @@ pub async fn run_agent_loop_streaming(
                     cost_usd: None,
                     silent: false,
                     directives: Default::default(),
+                citations: citations.clone(),
                 });
             }
             StopReason::ToolUse => {
@@ -1581,6 +1618,7 @@ pub async fn run_agent_loop_streaming(
                         cost_usd: None,
                         silent: false,
                         directives: Default::default(),
+                    citations: citations.clone(),
                     });
                 }
                 let text = response.text();
@@ -1861,6 +1899,7 @@ mod tests {
                     usage: TokenUsage {
                         input_tokens: 10,
                         output_tokens: 5,
+                        reasoning_tokens: 0,
                     },
                 })
             } else {
@@ -1872,6 +1911,7 @@ mod tests {
                     usage: TokenUsage {
                         input_tokens: 10,
                         output_tokens: 0,
+                        reasoning_tokens: 0,
                     },
                 })
             }
@@ -1895,6 +1935,7 @@ mod tests {
                 usage: TokenUsage {
                     input_tokens: 10,
                     output_tokens: 0,
+                    reasoning_tokens: 0,
                 },
             })
         }
@@ -1918,6 +1959,7 @@ mod tests {
                 usage: TokenUsage {
                     input_tokens: 10,
                     output_tokens: 8,
+                    reasoning_tokens: 0,
                 },
             })
         }
@@ -2153,6 +2195,7 @@ mod tests {
                     usage: TokenUsage {
                         input_tokens: 10,
                         output_tokens: 0,
+                        reasoning_tokens: 0,
                     },
                 })
             } else {
@@ -2166,6 +2209,7 @@ mod tests {
                     usage: TokenUsage {
                         input_tokens: 15,
                         output_tokens: 8,
+                        reasoning_tokens: 0,
                     },
                 })
             }
@@ -2189,6 +2233,7 @@ mod tests {
                 usage: TokenUsage {
                     input_tokens: 10,
                     output_tokens: 0,
+                    reasoning_tokens: 0,
                 },
             })
         }
@@ -2630,6 +2675,7 @@ mod tests {
                     usage: TokenUsage {
                         input_tokens: 20,
                         output_tokens: 15,
+                        reasoning_tokens: 0,
                     },
                 })
             } else {
@@ -2643,6 +2689,7 @@ mod tests {
                     usage: TokenUsage {
                         input_tokens: 30,
                         output_tokens: 12,
+                        reasoning_tokens: 0,
                     },
                 })
             }
@@ -446,12 +446,14 @@ async fn summarize_messages(
         tools: vec![],
         max_tokens: config.max_summary_tokens,
         temperature: 0.3,
+        top_p: None,
         system: Some(
             "You are a conversation summarizer. Produce a concise summary that captures \
              all key facts, decisions, and context from the conversation."
                 .to_string(),
         ),
         thinking: None,
+        reasoning: None,
     };
 
     // Retry logic for transient failures
@@ -561,12 +563,14 @@ async fn summarize_in_chunks(
         tools: vec![],
         max_tokens: config.max_summary_tokens,
         temperature: 0.3,
+        top_p: None,
         system: Some(
             "You are a conversation summarizer. Merge the provided partial summaries \
              into a single cohesive summary."
                 .to_string(),
         ),
         thinking: None,
+        reasoning: None,
     };
 
     match driver.complete(merge_request).await {
@@ -765,6 +769,7 @@ mod tests {
                     usage: TokenUsage {
                         input_tokens: 100,
                         output_tokens: 50,
+                        reasoning_tokens: 0,
                     },
                 })
             }
@@ -826,6 +831,7 @@ mod tests {
                     usage: TokenUsage {
                         input_tokens: 100,
                         output_tokens: 50,
+                        reasoning_tokens: 0,
                     },
                 })
             }
@@ -916,6 +922,7 @@ mod tests {
                     usage: TokenUsage {
                         input_tokens: 500,
                         output_tokens: 100,
+                        reasoning_tokens: 0,
                     },
                 })
             }
@@ -1111,6 +1118,7 @@ mod tests {
                     usage: TokenUsage {
                         input_tokens: 50,
                         output_tokens: 20,
+                        reasoning_tokens: 0,
                     },
                 })
             }
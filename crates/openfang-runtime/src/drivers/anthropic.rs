@@ -44,10 +44,42 @@ struct ApiRequest {
     tools: Vec<ApiTool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thinking: Option<ApiThinking>,
     #[serde(skip_serializing_if = "std::ops::Not::not")]
     stream: bool,
 }
 
+/// Anthropic extended-thinking request block.
+#[derive(Debug, Serialize)]
+struct ApiThinking {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    budget_tokens: u32,
+}
+
+impl ApiThinking {
+    fn from_config(config: &openfang_types::config::ThinkingConfig) -> Self {
+        Self {
+            kind: "enabled",
+            budget_tokens: config.budget_tokens,
+        }
+    }
+}
+
+/// Resolve the (temperature, top_p, thinking) triple for a request.
+///
+/// Extended thinking requires `temperature = 1` and doesn't support `top_p`,
+/// so those are dropped in favor of the API default whenever thinking is on.
+fn sampling_params(request: &CompletionRequest) -> (Option<f32>, Option<f32>, Option<ApiThinking>) {
+    match request.thinking.as_ref() {
+        Some(config) => (None, None, Some(ApiThinking::from_config(config))),
+        None => (Some(request.temperature), request.top_p, None),
+    }
+}
+
 #[derive(Debug, Serialize)]
 struct ApiMessage {
     role: String,
@@ -185,13 +217,16 @@ impl LlmDriver for AnthropicDriver {
             })
             .collect();
 
+        let (temperature, top_p, thinking) = sampling_params(&request);
         let api_request = ApiRequest {
             model: request.model.clone(),
             max_tokens: request.max_tokens,
             system,
             messages: api_messages,
             tools: api_tools,
-            temperature: Some(request.temperature),
+            temperature,
+            top_p,
+            thinking,
             stream: false,
         };
 
@@ -292,13 +327,21 @@ impl LlmDriver for AnthropicDriver {
             })
             .collect();
 
+        let stream_thinking = request
+            .thinking
+            .as_ref()
+            .map(|t| t.stream_thinking)
+            .unwrap_or(false);
+        let (temperature, top_p, thinking) = sampling_params(&request);
         let api_request = ApiRequest {
             model: request.model.clone(),
             max_tokens: request.max_tokens,
             system,
             messages: api_messages,
             tools: api_tools,
-            temperature: Some(request.temperature),
+            temperature,
+            top_p,
+            thinking,
             stream: true,
         };
 
@@ -454,6 +497,13 @@ impl LlmDriver for AnthropicDriver {
                                         {
                                             t.push_str(thinking);
                                         }
+                                        if stream_thinking {
+                                            let _ = tx
+                                                .send(StreamEvent::ThinkingDelta {
+                                                    text: thinking.to_string(),
+                                                })
+                                                .await;
+                                        }
                                     }
                                 }
                                 _ => {}
@@ -634,6 +684,9 @@ fn convert_response(api: ApiResponse) -> CompletionResponse {
         usage: TokenUsage {
             input_tokens: api.usage.input_tokens,
             output_tokens: api.usage.output_tokens,
+            // Anthropic doesn't break out thinking tokens separately — they're
+            // already included in `output_tokens`.
+            reasoning_tokens: 0,
         },
     }
 }
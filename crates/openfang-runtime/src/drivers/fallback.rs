@@ -117,6 +117,7 @@ mod tests {
                 usage: TokenUsage {
                     input_tokens: 10,
                     output_tokens: 5,
+                    reasoning_tokens: 0,
                 },
             })
         }
@@ -129,8 +130,10 @@ mod tests {
             tools: vec![],
             max_tokens: 100,
             temperature: 0.0,
+            top_p: None,
             system: None,
             thinking: None,
+            reasoning: None,
         }
     }
 
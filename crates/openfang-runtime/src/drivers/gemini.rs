@@ -122,6 +122,8 @@ struct GenerationConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     temperature: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     max_output_tokens: Option<u32>,
 }
 
@@ -152,6 +154,8 @@ struct GeminiUsageMetadata {
     prompt_token_count: u64,
     #[serde(default)]
     candidates_token_count: u64,
+    #[serde(default)]
+    thoughts_token_count: u64,
 }
 
 /// Gemini API error response.
@@ -342,6 +346,7 @@ fn convert_response(resp: GeminiResponse) -> Result<CompletionResponse, LlmError
         .map(|u| TokenUsage {
             input_tokens: u.prompt_token_count,
             output_tokens: u.candidates_token_count,
+            reasoning_tokens: u.thoughts_token_count,
         })
         .unwrap_or_default();
 
@@ -367,6 +372,7 @@ impl LlmDriver for GeminiDriver {
             tools,
             generation_config: Some(GenerationConfig {
                 temperature: Some(request.temperature),
+                top_p: request.top_p,
                 max_output_tokens: Some(request.max_tokens),
             }),
         };
@@ -447,6 +453,7 @@ impl LlmDriver for GeminiDriver {
             tools,
             generation_config: Some(GenerationConfig {
                 temperature: Some(request.temperature),
+                top_p: request.top_p,
                 max_output_tokens: Some(request.max_tokens),
             }),
         };
@@ -537,6 +544,7 @@ impl LlmDriver for GeminiDriver {
                     if let Some(ref u) = json.usage_metadata {
                         usage.input_tokens = u.prompt_token_count;
                         usage.output_tokens = u.candidates_token_count;
+                        usage.reasoning_tokens = u.thoughts_token_count;
                     }
 
                     for candidate in &json.candidates {
@@ -676,6 +684,7 @@ mod tests {
             tools: vec![],
             generation_config: Some(GenerationConfig {
                 temperature: Some(0.7),
+                top_p: None,
                 max_output_tokens: Some(1024),
             }),
         };
@@ -795,8 +804,10 @@ mod tests {
             }],
             max_tokens: 1024,
             temperature: 0.7,
+            top_p: None,
             system: None,
             thinking: None,
+            reasoning: None,
         };
 
         let tools = convert_tools(&request);
@@ -813,8 +824,10 @@ mod tests {
             tools: vec![],
             max_tokens: 1024,
             temperature: 0.7,
+            top_p: None,
             system: None,
             thinking: None,
+            reasoning: None,
         };
 
         let tools = convert_tools(&request);
@@ -836,6 +849,7 @@ mod tests {
             usage_metadata: Some(GeminiUsageMetadata {
                 prompt_token_count: 5,
                 candidates_token_count: 3,
+                thoughts_token_count: 0,
             }),
         };
 
@@ -930,6 +944,7 @@ mod tests {
     fn test_generation_config_serialization() {
         let config = GenerationConfig {
             temperature: Some(0.5),
+            top_p: None,
             max_output_tokens: Some(2048),
         };
         let json = serde_json::to_value(&config).unwrap();
@@ -35,6 +35,10 @@ struct OaiRequest {
     messages: Vec<OaiMessage>,
     max_tokens: u32,
     temperature: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reasoning_effort: Option<&'static str>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     tools: Vec<OaiTool>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -127,6 +131,14 @@ struct OaiResponseMessage {
 struct OaiUsage {
     prompt_tokens: u64,
     completion_tokens: u64,
+    #[serde(default)]
+    completion_tokens_details: Option<OaiCompletionTokensDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OaiCompletionTokensDetails {
+    #[serde(default)]
+    reasoning_tokens: u64,
 }
 
 #[async_trait]
@@ -281,6 +293,8 @@ impl LlmDriver for OpenAIDriver {
             messages: oai_messages,
             max_tokens: request.max_tokens,
             temperature: request.temperature,
+            top_p: request.top_p,
+            reasoning_effort: request.reasoning.map(|r| r.openai_effort_str()),
             tools: oai_tools,
             tool_choice,
             stream: false,
@@ -415,6 +429,10 @@ impl LlmDriver for OpenAIDriver {
                 .map(|u| TokenUsage {
                     input_tokens: u.prompt_tokens,
                     output_tokens: u.completion_tokens,
+                    reasoning_tokens: u
+                        .completion_tokens_details
+                        .map(|d| d.reasoning_tokens)
+                        .unwrap_or(0),
                 })
                 .unwrap_or_default();
 
@@ -560,6 +578,8 @@ impl LlmDriver for OpenAIDriver {
             messages: oai_messages,
             max_tokens: request.max_tokens,
             temperature: request.temperature,
+            top_p: request.top_p,
+            reasoning_effort: request.reasoning.map(|r| r.openai_effort_str()),
             tools: oai_tools,
             tool_choice,
             stream: true,
@@ -898,10 +918,7 @@ fn parse_groq_failed_tool_call(body: &str) -> Option<CompletionResponse> {
                 }],
                 tool_calls: vec![],
                 stop_reason: StopReason::EndTurn,
-                usage: TokenUsage {
-                    input_tokens: 0,
-                    output_tokens: 0,
-                },
+                usage: TokenUsage::default(),
             });
         }
         return None;
@@ -911,10 +928,7 @@ fn parse_groq_failed_tool_call(body: &str) -> Option<CompletionResponse> {
         content: vec![],
         tool_calls,
         stop_reason: StopReason::ToolUse,
-        usage: TokenUsage {
-            input_tokens: 0,
-            output_tokens: 0,
-        },
+        usage: TokenUsage::default(),
     })
 }
 
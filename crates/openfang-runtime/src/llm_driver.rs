@@ -55,10 +55,16 @@ pub struct CompletionRequest {
     pub max_tokens: u32,
     /// Sampling temperature.
     pub temperature: f32,
+    /// Nucleus sampling threshold. `None` lets the provider apply its own default.
+    pub top_p: Option<f32>,
     /// System prompt (extracted from messages for APIs that need it separately).
     pub system: Option<String>,
     /// Extended thinking configuration (if supported by the model).
     pub thinking: Option<openfang_types::config::ThinkingConfig>,
+    /// Unified reasoning effort. The OpenAI-compatible driver sends it as
+    /// `reasoning_effort`; the Anthropic driver reads `thinking` instead (see
+    /// `ModelConfig::thinking_config`), and other drivers ignore it.
+    pub reasoning: Option<openfang_types::agent::ReasoningEffort>,
 }
 
 /// A response from an LLM completion.
@@ -228,6 +234,7 @@ mod tests {
                 usage: TokenUsage {
                     input_tokens: 10,
                     output_tokens: 5,
+                    reasoning_tokens: 0,
                 },
             },
         ];
@@ -255,6 +262,7 @@ mod tests {
                     usage: TokenUsage {
                         input_tokens: 5,
                         output_tokens: 3,
+                        reasoning_tokens: 0,
                     },
                 })
             }
@@ -268,8 +276,10 @@ mod tests {
             tools: vec![],
             max_tokens: 100,
             temperature: 0.0,
+            top_p: None,
             system: None,
             thinking: None,
+            reasoning: None,
         };
 
         let response = driver.stream(request, tx).await.unwrap();
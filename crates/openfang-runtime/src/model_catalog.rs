@@ -85,6 +85,39 @@ impl ModelCatalog {
         self.aliases.get(&alias.to_lowercase()).map(|s| s.as_str())
     }
 
+    /// Validate a set of model parameters against the catalog's capability entry.
+    ///
+    /// Temperature (0.0-2.0) and top_p (0.0-1.0) ranges are checked for every
+    /// model. The max_tokens limit is checked only for models found in the
+    /// catalog; unknown models (not yet discovered/merged) skip that check.
+    pub fn validate_model_params(
+        &self,
+        model_id: &str,
+        max_tokens: u32,
+        temperature: f32,
+        top_p: Option<f32>,
+    ) -> Result<(), String> {
+        if !(0.0..=2.0).contains(&temperature) {
+            return Err(format!(
+                "temperature {temperature} out of range (expected 0.0-2.0)"
+            ));
+        }
+        if let Some(p) = top_p {
+            if !(0.0..=1.0).contains(&p) {
+                return Err(format!("top_p {p} out of range (expected 0.0-1.0)"));
+            }
+        }
+        if let Some(entry) = self.find_model(model_id) {
+            if entry.max_output_tokens > 0 && max_tokens as u64 > entry.max_output_tokens {
+                return Err(format!(
+                    "max_tokens {max_tokens} exceeds {}'s limit of {} output tokens",
+                    entry.id, entry.max_output_tokens
+                ));
+            }
+        }
+        Ok(())
+    }
+
     /// List all providers.
     pub fn list_providers(&self) -> &[ProviderInfo] {
         &self.providers
@@ -186,8 +186,10 @@ mod tests {
             tools,
             max_tokens: 4096,
             temperature: 0.7,
+            top_p: None,
             system: None,
             thinking: None,
+            reasoning: None,
         }
     }
 
@@ -372,6 +372,18 @@ pub struct ModelConfig {
     pub max_tokens: u32,
     /// Sampling temperature.
     pub temperature: f32,
+    /// Nucleus sampling threshold. `None` lets the provider apply its own default.
+    #[serde(default)]
+    pub top_p: Option<f32>,
+    /// Unified reasoning effort for reasoning-capable models. `None` disables
+    /// extended reasoning and uses the provider's normal completion path.
+    #[serde(default)]
+    pub reasoning: Option<ReasoningEffort>,
+    /// Whether to stream the model's reasoning/thinking content to the client
+    /// as `ThinkingDelta` events instead of suppressing it. `ContentBlock::Thinking`
+    /// blocks stay in the response content either way. Ignored when `reasoning` is `None`.
+    #[serde(default)]
+    pub show_reasoning: bool,
     /// System prompt for the agent.
     pub system_prompt: String,
     /// Optional API key environment variable name.
@@ -380,6 +392,55 @@ pub struct ModelConfig {
     pub base_url: Option<String>,
 }
 
+/// Unified reasoning effort level for reasoning-capable models.
+///
+/// Mapped to provider-specific parameters at request time: OpenAI's
+/// `reasoning_effort` field for `o1`/`o3`-family models, and an Anthropic
+/// extended-thinking token budget for Claude models that support it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReasoningEffort {
+    Low,
+    Medium,
+    High,
+}
+
+impl ReasoningEffort {
+    /// The Anthropic extended-thinking token budget for this effort level.
+    pub fn anthropic_budget_tokens(self) -> u32 {
+        match self {
+            ReasoningEffort::Low => 1_024,
+            ReasoningEffort::Medium => 4_096,
+            ReasoningEffort::High => 16_000,
+        }
+    }
+
+    /// The OpenAI `reasoning_effort` request field value for this effort level.
+    pub fn openai_effort_str(self) -> &'static str {
+        match self {
+            ReasoningEffort::Low => "low",
+            ReasoningEffort::Medium => "medium",
+            ReasoningEffort::High => "high",
+        }
+    }
+}
+
+/// Per-request overrides for an agent's model parameters.
+///
+/// Merge order is request > agent (`ModelConfig`) > provider default: any field
+/// left `None` here falls through to the agent manifest's `[model]` value.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct ModelParamOverrides {
+    /// Sampling temperature override.
+    pub temperature: Option<f32>,
+    /// Nucleus sampling threshold override.
+    pub top_p: Option<f32>,
+    /// Maximum completion tokens override.
+    pub max_tokens: Option<u32>,
+    /// Reasoning effort override.
+    pub reasoning: Option<ReasoningEffort>,
+}
+
 impl Default for ModelConfig {
     fn default() -> Self {
         Self {
@@ -387,6 +448,9 @@ impl Default for ModelConfig {
             model: "claude-sonnet-4-20250514".to_string(),
             max_tokens: 4096,
             temperature: 0.7,
+            top_p: None,
+            reasoning: None,
+            show_reasoning: false,
             system_prompt: "You are a helpful AI agent.".to_string(),
             api_key_env: None,
             base_url: None,
@@ -394,6 +458,17 @@ impl Default for ModelConfig {
     }
 }
 
+impl ModelConfig {
+    /// Build the Anthropic-style extended-thinking config for this agent's
+    /// reasoning setting, or `None` if reasoning is disabled.
+    pub fn thinking_config(&self) -> Option<crate::config::ThinkingConfig> {
+        self.reasoning.map(|effort| crate::config::ThinkingConfig {
+            budget_tokens: effort.anthropic_budget_tokens(),
+            stream_thinking: self.show_reasoning,
+        })
+    }
+}
+
 /// A fallback model entry in a chain.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FallbackModel {
@@ -200,6 +200,11 @@ pub struct TokenUsage {
     pub input_tokens: u64,
     /// Tokens generated in the output.
     pub output_tokens: u64,
+    /// Reasoning/thinking tokens included in `output_tokens`, reported
+    /// separately when the provider breaks them out (e.g. OpenAI reasoning
+    /// models). Zero when the provider doesn't report this split.
+    #[serde(default)]
+    pub reasoning_tokens: u64,
 }
 
 impl TokenUsage {
@@ -209,6 +214,25 @@ impl TokenUsage {
     }
 }
 
+/// A structured citation linking part of an agent's response back to a source.
+///
+/// Populated from the memories the agent recalls and folds into the prompt,
+/// and returned with the response in the API message payload. Only recalled
+/// memories produce citations; the TUI and transcript exports do not render them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Citation {
+    /// Identifier of the source (memory ID, document ID, URL, etc.).
+    pub source_id: String,
+    /// Human-readable document/source name.
+    pub document: String,
+    /// Character offset span within the source content that was used.
+    pub offset_start: usize,
+    /// End of the character offset span (exclusive).
+    pub offset_end: usize,
+    /// Short excerpt of the cited content, for display without re-fetching the source.
+    pub snippet: String,
+}
+
 /// Reply directives extracted from agent output.
 ///
 /// These control how the response is delivered back to the user/channel:
@@ -244,6 +268,7 @@ mod tests {
         let usage = TokenUsage {
             input_tokens: 100,
             output_tokens: 50,
+            reasoning_tokens: 0,
         };
         assert_eq!(usage.total(), 150);
     }